@@ -0,0 +1,70 @@
+use serde::ser::SerializeStruct;
+use serde::{Serialize, Serializer};
+use std::fmt;
+
+/// Structured error returned by every `db_*` command, so the frontend can
+/// branch on `code` instead of pattern-matching a free-form string.
+#[derive(Debug)]
+pub enum AppError {
+    InvalidObjectId(String),
+    Serialization(String),
+    Database(String),
+}
+
+impl AppError {
+    fn code(&self) -> &'static str {
+        match self {
+            AppError::InvalidObjectId(_) => "invalid_object_id",
+            AppError::Serialization(_) => "serialization",
+            AppError::Database(_) => "database",
+        }
+    }
+}
+
+impl fmt::Display for AppError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            AppError::InvalidObjectId(msg) => write!(f, "Invalid ObjectId: {}", msg),
+            AppError::Serialization(msg) => write!(f, "Failed to serialize/deserialize: {}", msg),
+            AppError::Database(msg) => write!(f, "Database error: {}", msg),
+        }
+    }
+}
+
+impl std::error::Error for AppError {}
+
+impl Serialize for AppError {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+    {
+        let mut state = serializer.serialize_struct("AppError", 2)?;
+        state.serialize_field("code", self.code())?;
+        state.serialize_field("message", &self.to_string())?;
+        state.end()
+    }
+}
+
+impl From<mongodb::error::Error> for AppError {
+    fn from(e: mongodb::error::Error) -> Self {
+        AppError::Database(e.to_string())
+    }
+}
+
+impl From<mongodb::bson::ser::Error> for AppError {
+    fn from(e: mongodb::bson::ser::Error) -> Self {
+        AppError::Serialization(e.to_string())
+    }
+}
+
+impl From<mongodb::bson::de::Error> for AppError {
+    fn from(e: mongodb::bson::de::Error) -> Self {
+        AppError::Serialization(e.to_string())
+    }
+}
+
+impl From<mongodb::bson::oid::Error> for AppError {
+    fn from(e: mongodb::bson::oid::Error) -> Self {
+        AppError::InvalidObjectId(e.to_string())
+    }
+}
@@ -0,0 +1,214 @@
+use futures::TryStreamExt;
+use mongodb::{
+    bson::{self, doc, oid::ObjectId, Document},
+    options::FindOptions,
+    Collection, Database,
+};
+use serde::{de::DeserializeOwned, Serialize};
+
+use crate::error::AppError;
+
+/// Outcome of an update/replace, including the id of the new document if
+/// the write upserted one.
+#[derive(Debug, Serialize)]
+pub struct UpdateResult {
+    pub matched: u64,
+    pub modified: u64,
+    pub upserted_id: Option<String>,
+}
+
+impl From<mongodb::results::UpdateResult> for UpdateResult {
+    fn from(result: mongodb::results::UpdateResult) -> Self {
+        Self {
+            matched: result.matched_count,
+            modified: result.modified_count,
+            upserted_id: result
+                .upserted_id
+                .and_then(|id| id.as_object_id().map(|oid| oid.to_hex())),
+        }
+    }
+}
+
+/// Thin wrapper around a `Document` collection that centralizes the
+/// `_id` ObjectId<->hex-string translation shared by every `db_*` command.
+pub struct Model<D> {
+    collection: Collection<Document>,
+    _marker: std::marker::PhantomData<D>,
+}
+
+impl<D: Serialize + DeserializeOwned> Model<D> {
+    pub fn new(db: &Database, collection: &str) -> Self {
+        Self {
+            collection: db.collection::<Document>(collection),
+            _marker: std::marker::PhantomData,
+        }
+    }
+
+    /// Converts a raw document's `_id` ObjectId into a hex string and
+    /// deserializes it into `D`.
+    fn doc_to_typed(doc: Document) -> Result<D, AppError> {
+        let id = doc
+            .get_object_id("_id")
+            .map_err(|e| AppError::Serialization(format!("Error getting _id: {}", e)))?
+            .to_hex();
+
+        let mut doc = doc;
+        doc.remove("_id");
+        doc.insert("_id", id);
+
+        Ok(bson::from_document::<D>(doc)?)
+    }
+
+    /// Serializes `item` into a document with its `_id` stripped, ready
+    /// for `insert_one`/`insert_many`.
+    fn typed_to_doc(item: &D) -> Result<Document, AppError> {
+        let mut doc = bson::to_document(item)?;
+        doc.remove("_id");
+        Ok(doc)
+    }
+
+    /// Creates a text index over `fields` if one doesn't already exist.
+    /// `create_index` is idempotent for an identical index definition, so
+    /// this is safe to call on every search rather than tracking state.
+    async fn ensure_text_index(&self, fields: &[&str]) -> Result<(), AppError> {
+        let mut keys = Document::new();
+        for field in fields {
+            keys.insert(*field, "text");
+        }
+
+        let index = mongodb::IndexModel::builder().keys(keys).build();
+        self.collection.create_index(index).await?;
+
+        Ok(())
+    }
+
+    /// Full-text search over `fields`, ranked by MongoDB's `textScore`.
+    pub async fn search(&self, fields: &[&str], query: &str, limit: Option<i64>) -> Result<Vec<D>, AppError> {
+        self.ensure_text_index(fields).await?;
+
+        let filter = doc! { "$text": { "$search": query } };
+        let score = doc! { "score": { "$meta": "textScore" } };
+
+        let options = FindOptions::builder()
+            .limit(limit)
+            .projection(score.clone())
+            .sort(score)
+            .build();
+
+        self.find(filter, options).await
+    }
+
+    pub async fn count(&self, filter: Document) -> Result<u64, AppError> {
+        Ok(self.collection.count_documents(filter).await?)
+    }
+
+    pub async fn find(&self, filter: Document, options: FindOptions) -> Result<Vec<D>, AppError> {
+        let mut cursor = self.collection.find(filter).with_options(options).await?;
+
+        let mut results = Vec::new();
+        while let Some(doc) = cursor.try_next().await? {
+            match Self::doc_to_typed(doc) {
+                Ok(item) => results.push(item),
+                Err(e) => {
+                    println!("{}", e);
+                    continue;
+                }
+            }
+        }
+
+        Ok(results)
+    }
+
+    pub async fn insert_one(&self, item: &D) -> Result<String, AppError> {
+        let doc = Self::typed_to_doc(item)?;
+        let result = self.collection.insert_one(doc).await?;
+
+        result
+            .inserted_id
+            .as_object_id()
+            .map(|id| id.to_hex())
+            .ok_or_else(|| AppError::Database("Failed to get inserted ID".to_string()))
+    }
+
+    /// Bulk insert via `insert_many`; returns the generated hex ids in the
+    /// same order as `items`.
+    pub async fn insert_many(&self, items: &[D]) -> Result<Vec<String>, AppError> {
+        let docs = items
+            .iter()
+            .map(Self::typed_to_doc)
+            .collect::<Result<Vec<_>, _>>()?;
+
+        let result = self.collection.insert_many(docs).await?;
+
+        let mut ids: Vec<(usize, String)> = result
+            .inserted_ids
+            .into_iter()
+            .filter_map(|(index, bson)| bson.as_object_id().map(|oid| (index, oid.to_hex())))
+            .collect();
+        ids.sort_by_key(|(index, _)| *index);
+
+        Ok(ids.into_iter().map(|(_, id)| id).collect())
+    }
+
+    /// Bulk delete via `delete_many` with a `{ _id: { $in: [...] } }` filter.
+    pub async fn delete_many(&self, ids: &[String]) -> Result<u64, AppError> {
+        let object_ids = ids
+            .iter()
+            .map(|id| ObjectId::parse_str(id).map_err(AppError::from))
+            .collect::<Result<Vec<_>, _>>()?;
+
+        let filter = doc! { "_id": { "$in": object_ids } };
+        let result = self.collection.delete_many(filter).await?;
+
+        Ok(result.deleted_count)
+    }
+
+    pub async fn update_one(&self, id: &str, item: &D, upsert: bool) -> Result<UpdateResult, AppError> {
+        let object_id = ObjectId::parse_str(id)?;
+        let doc = Self::typed_to_doc(item)?;
+
+        let filter = doc! { "_id": object_id };
+        let update = doc! { "$set": doc };
+
+        let options = mongodb::options::UpdateOptions::builder()
+            .upsert(upsert)
+            .build();
+
+        let result = self
+            .collection
+            .update_one(filter, update)
+            .with_options(options)
+            .await?;
+
+        Ok(result.into())
+    }
+
+    /// Overwrites the whole document rather than patching fields.
+    pub async fn replace_one(&self, id: &str, item: &D, upsert: bool) -> Result<UpdateResult, AppError> {
+        let object_id = ObjectId::parse_str(id)?;
+        let doc = Self::typed_to_doc(item)?;
+
+        let filter = doc! { "_id": object_id };
+
+        let options = mongodb::options::ReplaceOptions::builder()
+            .upsert(upsert)
+            .build();
+
+        let result = self
+            .collection
+            .replace_one(filter, doc)
+            .with_options(options)
+            .await?;
+
+        Ok(result.into())
+    }
+
+    pub async fn delete_one(&self, id: &str) -> Result<bool, AppError> {
+        let object_id = ObjectId::parse_str(id)?;
+        let filter = doc! { "_id": object_id };
+
+        let result = self.collection.delete_one(filter).await?;
+
+        Ok(result.deleted_count > 0)
+    }
+}